@@ -127,6 +127,46 @@ use librocksdb_sys as ffi;
 
 use std::error;
 use std::fmt;
+use std::io;
+
+/// RocksDB error kind.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    NotFound,
+    Corruption,
+    NotSupported,
+    InvalidArgument,
+    IOError,
+    MergeInProgress,
+    Incomplete,
+    ShutdownInProgress,
+    TimedOut,
+    Aborted,
+    Busy,
+    Expired,
+    TryAgain,
+    CompactionTooLarge,
+    ColumnFamilyDropped,
+    Unknown,
+}
+
+/// RocksDB error subcode, refining an [`ErrorKind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ErrorSubCode {
+    MutexTimeout,
+    LockTimeout,
+    LockLimit,
+    NoSpace,
+    Deadlock,
+    StaleFile,
+    MemoryLimit,
+    SpaceLimit,
+    PathNotFound,
+    MergeOperandsInsufficientCapacity,
+    ManualCompactionPaused,
+    TxnNotPrepared,
+    IOFenced,
+}
 
 /// A simple wrapper round a string, used for errors reported from
 /// ffi calls.
@@ -143,6 +183,71 @@ impl Error {
     pub fn into_string(self) -> String {
         self.into()
     }
+
+    /// Parse corresponding [`ErrorKind`] from error message.
+    ///
+    /// Errors reported by RocksDB start with the string form of their status
+    /// code. Errors raised by this crate itself map to `ErrorKind::Unknown`.
+    pub fn kind(&self) -> ErrorKind {
+        match self.message.split(':').next().unwrap_or("") {
+            "NotFound" => ErrorKind::NotFound,
+            "Corruption" => ErrorKind::Corruption,
+            "Not implemented" => ErrorKind::NotSupported,
+            "Invalid argument" => ErrorKind::InvalidArgument,
+            "IO error" => ErrorKind::IOError,
+            "Merge in progress" => ErrorKind::MergeInProgress,
+            "Result incomplete" => ErrorKind::Incomplete,
+            "Shutdown in progress" => ErrorKind::ShutdownInProgress,
+            "Operation timed out" => ErrorKind::TimedOut,
+            "Operation aborted" => ErrorKind::Aborted,
+            "Resource busy" => ErrorKind::Busy,
+            "Operation expired" => ErrorKind::Expired,
+            "Operation failed. Try again." => ErrorKind::TryAgain,
+            "Compaction too large" => ErrorKind::CompactionTooLarge,
+            "Column family dropped" => ErrorKind::ColumnFamilyDropped,
+            _ => ErrorKind::Unknown,
+        }
+    }
+
+    /// Parse corresponding [`ErrorSubCode`] from error message, if any.
+    ///
+    /// RocksDB writes the subcode description right after the status code.
+    /// The C API does not expose the status severity, so it can't be
+    /// recovered here.
+    pub fn subcode(&self) -> Option<ErrorSubCode> {
+        if self.kind() == ErrorKind::Unknown {
+            return None;
+        }
+        let (_, state) = self.message.split_once(": ")?;
+        let subcodes = [
+            ("Timeout Acquiring Mutex", ErrorSubCode::MutexTimeout),
+            ("Timeout waiting to lock key", ErrorSubCode::LockTimeout),
+            (
+                "Failed to acquire lock due to max_num_locks limit",
+                ErrorSubCode::LockLimit,
+            ),
+            ("No space left on device", ErrorSubCode::NoSpace),
+            ("Deadlock", ErrorSubCode::Deadlock),
+            ("Stale file handle", ErrorSubCode::StaleFile),
+            ("Memory limit reached", ErrorSubCode::MemoryLimit),
+            ("Space limit reached", ErrorSubCode::SpaceLimit),
+            ("No such file or directory", ErrorSubCode::PathNotFound),
+            (
+                "Insufficient capacity for merge operands",
+                ErrorSubCode::MergeOperandsInsufficientCapacity,
+            ),
+            (
+                "Manual compaction paused",
+                ErrorSubCode::ManualCompactionPaused,
+            ),
+            ("Txn not prepared", ErrorSubCode::TxnNotPrepared),
+            ("IO fenced off", ErrorSubCode::IOFenced),
+        ];
+        subcodes
+            .iter()
+            .find(|(message, _)| state.starts_with(message))
+            .map(|&(_, subcode)| subcode)
+    }
 }
 
 impl AsRef<str> for Error {
@@ -169,14 +274,29 @@ impl fmt::Display for Error {
     }
 }
 
+impl From<Error> for io::Error {
+    fn from(e: Error) -> io::Error {
+        let kind = match e.kind() {
+            ErrorKind::NotFound => io::ErrorKind::NotFound,
+            ErrorKind::Corruption => io::ErrorKind::InvalidData,
+            ErrorKind::InvalidArgument => io::ErrorKind::InvalidInput,
+            ErrorKind::TimedOut => io::ErrorKind::TimedOut,
+            ErrorKind::Busy | ErrorKind::TryAgain => io::ErrorKind::WouldBlock,
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, e)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{
         BlockBasedOptions, BoundColumnFamily, Cache, ColumnFamily, ColumnFamilyDescriptor,
-        DBIterator, DBRawIterator, Env, IngestExternalFileOptions, Options,
-        PlainTableFactoryOptions, ReadOptions, Snapshot, SstFileWriter, WriteBatch, WriteOptions,
-        DB,
+        DBIterator, DBRawIterator, Env, Error, ErrorKind, ErrorSubCode, IngestExternalFileOptions,
+        Options, PlainTableFactoryOptions, ReadOptions, Snapshot, SstFileWriter, WriteBatch,
+        WriteOptions, DB,
     };
+    use std::io;
 
     #[test]
     fn is_send() {
@@ -227,4 +347,58 @@ mod test {
         is_sync::<Cache>();
         is_sync::<Env>();
     }
+
+    #[test]
+    fn error_kind() {
+        let kind = |msg: &str| Error::new(msg.to_owned()).kind();
+
+        assert_eq!(kind("NotFound: "), ErrorKind::NotFound);
+        assert_eq!(
+            kind("Corruption: CURRENT file does not end with newline"),
+            ErrorKind::Corruption
+        );
+        assert_eq!(
+            kind("Invalid argument: /tmp/db: does not exist (create_if_missing is false)"),
+            ErrorKind::InvalidArgument
+        );
+        assert_eq!(
+            kind("Operation timed out: Timeout waiting to lock key"),
+            ErrorKind::TimedOut
+        );
+        assert_eq!(kind("Operation failed. Try again.: "), ErrorKind::TryAgain);
+        assert_eq!(kind("Could not initialize database."), ErrorKind::Unknown);
+    }
+
+    #[test]
+    fn error_subcode() {
+        let subcode = |msg: &str| Error::new(msg.to_owned()).subcode();
+
+        assert_eq!(
+            subcode("Operation timed out: Timeout waiting to lock key"),
+            Some(ErrorSubCode::LockTimeout)
+        );
+        assert_eq!(
+            subcode("Resource busy: Deadlock"),
+            Some(ErrorSubCode::Deadlock)
+        );
+        assert_eq!(
+            subcode(
+                "IO error: No space left on device: While appending to file: /tmp/db/000003.log"
+            ),
+            Some(ErrorSubCode::NoSpace)
+        );
+        assert_eq!(subcode("Corruption: bad block"), None);
+        assert_eq!(subcode("Resource busy: "), None);
+        assert_eq!(subcode("Deadlock"), None);
+    }
+
+    #[test]
+    fn error_into_io_error() {
+        let err: io::Error = Error::new("Resource busy: ".to_owned()).into();
+        assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+        assert_eq!(err.to_string(), "Resource busy: ");
+
+        let err: io::Error = Error::new("Corruption: bad block".to_owned()).into();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
 }
@@ -14,13 +14,13 @@
 
 mod util;
 
-use std::{mem, sync::Arc, thread, time::Duration};
+use std::{fs, mem, sync::Arc, thread, time::Duration};
 
 use pretty_assertions::assert_eq;
 
 use rocksdb::{
     perf::get_memory_usage_stats, BlockBasedOptions, BottommostLevelCompaction, Cache,
-    CompactOptions, CuckooTableOptions, DBCompactionStyle, DBWithThreadMode, Env, Error,
+    CompactOptions, CuckooTableOptions, DBCompactionStyle, DBWithThreadMode, Env, Error, ErrorKind,
     FifoCompactOptions, IteratorMode, MultiThreaded, Options, PerfContext, PerfMetric, ReadOptions,
     SingleThreaded, SliceTransform, Snapshot, UniversalCompactOptions,
     UniversalCompactionStopStyle, WriteBatch, DB,
@@ -80,6 +80,25 @@ fn errors_do_stuff() {
     }
 }
 
+#[test]
+fn open_corrupted_db() {
+    let path = DBPath::new("_rust_rocksdb_open_corrupted_db");
+    {
+        let db = DB::open_default(&path).unwrap();
+        db.put(b"k1", b"v1").unwrap();
+    }
+
+    // CURRENT must end with a newline, so this is detected at open.
+    fs::write((&path).as_ref().join("CURRENT"), b"MANIFEST-000001").unwrap();
+
+    let mut opts = Options::default();
+    opts.set_paranoid_checks(true);
+    match DB::open(&opts, &path) {
+        Err(e) => assert_eq!(e.kind(), ErrorKind::Corruption),
+        Ok(_) => panic!("should fail"),
+    }
+}
+
 #[test]
 fn writebatch_works() {
     let path = DBPath::new("_rust_rocksdb_writebacktest");